@@ -21,31 +21,80 @@ namespace {
 
 // Encoding functions
 constexpr size_t kEncodedGroupSize = 4;
-constexpr char kChar62 = '+';  // URL safe encoding uses - instead
-constexpr char kChar63 = '/';  // URL safe encoding uses _ instead
+constexpr char kChar62 = '+';
+constexpr char kChar63 = '/';
+constexpr char kUrlSafeChar62 = '-';
+constexpr char kUrlSafeChar63 = '_';
 constexpr char kPadding = '=';
 
-// Table that encodes a 6-bit pattern as a Base64 character
-constexpr char encode_bits[64] = {
+// Tables that encode a 6-bit pattern as a Base64 character
+constexpr char kStandardEncodeBits[64] = {
     'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K',     'L',    'M',
     'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X',     'Y',    'Z',
     'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k',     'l',    'm',
     'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x',     'y',    'z',
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', kChar62, kChar63};
 
-constexpr char BitGroup0Char(uint8_t byte0) {
+constexpr char kUrlSafeEncodeBits[64] = {
+    'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+    'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+    'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+    'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+    kUrlSafeChar62, kUrlSafeChar63};
+
+constexpr char BitGroup0Char(const char* encode_bits, uint8_t byte0) {
   return encode_bits[(byte0 & 0b11111100) >> 2];
 }
-constexpr char BitGroup1Char(uint8_t byte0, uint8_t byte1 = 0) {
+constexpr char BitGroup1Char(const char* encode_bits,
+                             uint8_t byte0,
+                             uint8_t byte1 = 0) {
   return encode_bits[((byte0 & 0b00000011) << 4) | ((byte1 & 0b11110000) >> 4)];
 }
-constexpr char BitGroup2Char(uint8_t byte1, uint8_t byte2 = 0) {
+constexpr char BitGroup2Char(const char* encode_bits,
+                             uint8_t byte1,
+                             uint8_t byte2 = 0) {
   return encode_bits[((byte1 & 0b00001111) << 2) | ((byte2 & 0b11000000) >> 6)];
 }
-constexpr char BitGroup3Char(uint8_t byte2) {
+constexpr char BitGroup3Char(const char* encode_bits, uint8_t byte2) {
   return encode_bits[byte2 & 0b00111111];
 }
 
+void EncodeWithTable(const void* binary_data,
+                     const size_t binary_size_bytes,
+                     char* output,
+                     const char* encode_bits,
+                     Padding padding) {
+  const uint8_t* bytes = static_cast<const uint8_t*>(binary_data);
+
+  // Encode groups of 3 source bytes into 4 output characters.
+  size_t remaining = binary_size_bytes;
+  for (; remaining >= 3u; remaining -= 3u, bytes += 3) {
+    *output++ = BitGroup0Char(encode_bits, bytes[0]);
+    *output++ = BitGroup1Char(encode_bits, bytes[0], bytes[1]);
+    *output++ = BitGroup2Char(encode_bits, bytes[1], bytes[2]);
+    *output++ = BitGroup3Char(encode_bits, bytes[2]);
+  }
+
+  // If the source data length isn't a multiple of 3, pad the end with either 1
+  // or 2 '=' characters, to stay Python-compatible, unless padding is disabled.
+  if (remaining > 0u) {
+    *output++ = BitGroup0Char(encode_bits, bytes[0]);
+    if (remaining == 1u) {
+      *output++ = BitGroup1Char(encode_bits, bytes[0]);
+      if (padding == Padding::kPadded) {
+        *output++ = kPadding;
+      }
+    } else {
+      *output++ = BitGroup1Char(encode_bits, bytes[0], bytes[1]);
+      *output++ = BitGroup2Char(encode_bits, bytes[1]);
+    }
+    if (padding == Padding::kPadded) {
+      *output++ = kPadding;
+    }
+  }
+}
+
 // Decoding functions
 constexpr char kMinValidChar = '+';
 constexpr char kMaxValidChar = 'z';
@@ -69,6 +118,11 @@ constexpr uint8_t CharToBits(char ch) {
   return decode_char[ch - kMinValidChar];
 }
 
+constexpr bool IsValidChar(char ch) {
+  return ch >= kMinValidChar && ch <= kMaxValidChar &&
+         CharToBits(ch) != kX /* invalid char */;
+}
+
 constexpr uint8_t Byte0(uint8_t bits0, uint8_t bits1) {
   return (bits0 << 2) | ((bits1 & 0b110000) >> 4);
 }
@@ -84,30 +138,11 @@ constexpr uint8_t Byte2(uint8_t bits2, uint8_t bits3) {
 extern "C" void pw_Base64Encode(const void* binary_data,
                                 const size_t binary_size_bytes,
                                 char* output) {
-  const uint8_t* bytes = static_cast<const uint8_t*>(binary_data);
-
-  // Encode groups of 3 source bytes into 4 output characters.
-  size_t remaining = binary_size_bytes;
-  for (; remaining >= 3u; remaining -= 3u, bytes += 3) {
-    *output++ = BitGroup0Char(bytes[0]);
-    *output++ = BitGroup1Char(bytes[0], bytes[1]);
-    *output++ = BitGroup2Char(bytes[1], bytes[2]);
-    *output++ = BitGroup3Char(bytes[2]);
-  }
-
-  // If the source data length isn't a multiple of 3, pad the end with either 1
-  // or 2 '=' characters, to stay Python-compatible.
-  if (remaining > 0u) {
-    *output++ = BitGroup0Char(bytes[0]);
-    if (remaining == 1u) {
-      *output++ = BitGroup1Char(bytes[0]);
-      *output++ = kPadding;
-    } else {
-      *output++ = BitGroup1Char(bytes[0], bytes[1]);
-      *output++ = BitGroup2Char(bytes[1]);
-    }
-    *output++ = kPadding;
-  }
+  EncodeWithTable(binary_data,
+                  binary_size_bytes,
+                  output,
+                  kStandardEncodeBits,
+                  Padding::kPadded);
 }
 
 extern "C" size_t pw_Base64Decode(const char* base64,
@@ -146,30 +181,89 @@ extern "C" bool pw_Base64IsValid(const char* base64_data, size_t base64_size) {
   }
 
   for (size_t i = 0; i < base64_size; ++i) {
-    if (base64_data[i] < kMinValidChar || base64_data[i] > kMaxValidChar ||
-        CharToBits(base64_data[i]) == kX /* invalid char */) {
+    if (!IsValidChar(base64_data[i])) {
       return false;
     }
   }
   return true;
 }
 
+void Encode(std::span<const std::byte> binary,
+            char* output,
+            Alphabet alphabet,
+            Padding padding) {
+  EncodeWithTable(binary.data(),
+                  binary.size_bytes(),
+                  output,
+                  alphabet == Alphabet::kUrlSafe ? kUrlSafeEncodeBits
+                                                 : kStandardEncodeBits,
+                  padding);
+}
+
 size_t Encode(std::span<const std::byte> binary,
-              std::span<char> output_buffer) {
-  const size_t required_size = EncodedSize(binary.size_bytes());
+              std::span<char> output_buffer,
+              Alphabet alphabet,
+              Padding padding) {
+  const size_t required_size = EncodedSize(binary.size_bytes(), padding);
   if (output_buffer.size_bytes() < required_size) {
     return 0;
   }
-  pw_Base64Encode(binary.data(), binary.size_bytes(), output_buffer.data());
+  Encode(binary, output_buffer.data(), alphabet, padding);
   return required_size;
 }
 
-size_t Decode(std::string_view base64, std::span<std::byte> output_buffer) {
-  if (output_buffer.size_bytes() < MaxDecodedSize(base64.size()) ||
-      !IsValid(base64)) {
+size_t Decode(std::string_view base64, void* output, Padding padding) {
+  if (padding == Padding::kPadded) {
+    return Decode(base64, output);
+  }
+
+  // Decode the complete 4-character groups, then the 2 or 3 character remnant.
+  const size_t remaining = base64.size() % kEncodedGroupSize;
+  const size_t group_chars = base64.size() - remaining;
+
+  uint8_t* binary = static_cast<uint8_t*>(output);
+  binary += pw_Base64Decode(base64.data(), group_chars, binary);
+
+  const char* remnant = base64.data() + group_chars;
+  if (remaining >= 2u) {
+    const uint8_t char0 = CharToBits(remnant[0]);
+    const uint8_t char1 = CharToBits(remnant[1]);
+    *binary++ = Byte0(char0, char1);
+
+    if (remaining == 3u) {
+      *binary++ = Byte1(char1, CharToBits(remnant[2]));
+    }
+  }
+
+  return binary - static_cast<uint8_t*>(output);
+}
+
+size_t Decode(std::string_view base64,
+              std::span<std::byte> output_buffer,
+              Padding padding) {
+  if (output_buffer.size_bytes() < MaxDecodedSize(base64.size(), padding) ||
+      !IsValid(base64, padding)) {
     return 0;
   }
-  return Decode(base64, output_buffer.data());
+  return Decode(base64, output_buffer.data(), padding);
+}
+
+bool IsValid(std::string_view base64, Padding padding) {
+  if (padding == Padding::kPadded) {
+    return IsValid(base64);
+  }
+
+  // A single leftover character cannot encode a full byte.
+  if (base64.size() % kEncodedGroupSize == 1u) {
+    return false;
+  }
+
+  for (char ch : base64) {
+    if (ch == kPadding || !IsValidChar(ch)) {
+      return false;
+    }
+  }
+  return true;
 }
 
 }  // namespace pw::base64
@@ -15,6 +15,7 @@
 #include "pw_base64/base64.h"
 
 #include <cstring>
+#include <string_view>
 
 #include "gtest/gtest.h"
 
@@ -359,6 +360,133 @@ TEST(Base64, Decode_UrlSafeDecode) {
   EXPECT_STREQ("\xf9\xff\xffYo!", output);
 }
 
+// Converts standard Base64 test data to the URL-safe alphabet and/or removes
+// the padding.
+std::string_view ConvertEncoded(const char* encoded_data,
+                                Alphabet alphabet,
+                                Padding padding,
+                                std::span<char> buffer) {
+  size_t size = 0;
+  for (const char* ch = encoded_data; *ch != '\0'; ++ch) {
+    if (*ch == '=' && padding == Padding::kUnpadded) {
+      continue;
+    }
+    if (alphabet == Alphabet::kUrlSafe && (*ch == '+' || *ch == '/')) {
+      buffer[size++] = *ch == '+' ? '-' : '_';
+    } else {
+      buffer[size++] = *ch;
+    }
+  }
+  return std::string_view(buffer.data(), size);
+}
+
+void ExpectEncodeDecode(const EncodedData& data,
+                        Alphabet alphabet,
+                        Padding padding) {
+  char expected_buffer[128];
+  const std::string_view expected =
+      ConvertEncoded(data.encoded_data, alphabet, padding, expected_buffer);
+  ASSERT_EQ(expected.size(), EncodedSize(data.binary_size, padding));
+
+  char encoded[128];
+  Encode(std::as_bytes(std::span(data.binary_data, data.binary_size)),
+         encoded,
+         alphabet,
+         padding);
+  EXPECT_EQ(expected, std::string_view(encoded, expected.size()));
+
+  std::byte decoded[128];
+  ASSERT_TRUE(IsValid(expected, padding));
+  ASSERT_LE(data.binary_size, MaxDecodedSize(expected.size(), padding));
+  ASSERT_EQ(data.binary_size, Decode(expected, decoded, padding));
+  EXPECT_EQ(0, std::memcmp(data.binary_data, decoded, data.binary_size));
+}
+
+TEST(Base64, EncodeDecode_AlphabetsAndPadding) {
+  for (Alphabet alphabet : {Alphabet::kStandard, Alphabet::kUrlSafe}) {
+    for (Padding padding : {Padding::kPadded, Padding::kUnpadded}) {
+      for (const EncodedData& data : kSingleCharTestData) {
+        ExpectEncodeDecode(data, alphabet, padding);
+      }
+      for (const EncodedData& data : kRandomTestData) {
+        ExpectEncodeDecode(data, alphabet, padding);
+      }
+    }
+  }
+}
+
+TEST(Base64, Encode_UrlSafe) {
+  constexpr uint8_t input[] = {0xf9, 0xff, 0xff, 0xfb, 0xef};
+  char output[EncodedSize(sizeof(input)) + 1] = {};
+
+  Encode(std::as_bytes(std::span(input)), output, Alphabet::kStandard);
+  EXPECT_STREQ("+f//++8=", output);
+  Encode(std::as_bytes(std::span(input)), output, Alphabet::kUrlSafe);
+  EXPECT_STREQ("-f__--8=", output);
+}
+
+TEST(Base64, EncodedSize_Unpadded) {
+  EXPECT_EQ(0u, EncodedSize(0, Padding::kUnpadded));
+  EXPECT_EQ(2u, EncodedSize(1, Padding::kUnpadded));
+  EXPECT_EQ(3u, EncodedSize(2, Padding::kUnpadded));
+  EXPECT_EQ(4u, EncodedSize(3, Padding::kUnpadded));
+  EXPECT_EQ(6u, EncodedSize(4, Padding::kUnpadded));
+  EXPECT_EQ(7u, EncodedSize(5, Padding::kUnpadded));
+  EXPECT_EQ(8u, EncodedSize(6, Padding::kUnpadded));
+}
+
+TEST(Base64, Encode_UnpaddedBoundaryCheck) {
+  constexpr std::byte data[] = {std::byte{'h'}, std::byte{'i'}};
+  char output[4] = {};
+
+  EXPECT_EQ(0u,
+            Encode(data,
+                   std::span(output, 2),
+                   Alphabet::kUrlSafe,
+                   Padding::kUnpadded));
+  EXPECT_STREQ("", output);
+  EXPECT_EQ(3u,
+            Encode(data,
+                   std::span(output, 3),
+                   Alphabet::kUrlSafe,
+                   Padding::kUnpadded));
+  EXPECT_STREQ("aGk", output);
+}
+
+TEST(Base64, Decode_UnpaddedBoundaryCheck) {
+  constexpr const char encoded_data[] = "aGk";
+  std::byte output[4] = {};
+
+  EXPECT_EQ(0u, Decode(encoded_data, std::span(output, 1), Padding::kUnpadded));
+  EXPECT_STREQ("", reinterpret_cast<const char*>(output));
+  EXPECT_EQ(2u, Decode(encoded_data, std::span(output, 2), Padding::kUnpadded));
+  EXPECT_STREQ("hi", reinterpret_cast<const char*>(output));
+}
+
+TEST(Base64, Decode_UnpaddedInPlace) {
+  constexpr const char expected[] = "This is a secret message!";
+  char buf[] = "VGhpcyBpcyBhIHNlY3JldCBtZXNzYWdlIQ";
+  EXPECT_EQ(sizeof(expected) - 1,
+            Decode(std::string_view(buf), buf, Padding::kUnpadded));
+  EXPECT_EQ(0, std::memcmp(expected, buf, sizeof(expected) - 1));
+}
+
+TEST(Base64, IsValid_Unpadded) {
+  EXPECT_TRUE(IsValid("", Padding::kUnpadded));
+  EXPECT_TRUE(IsValid("Zg", Padding::kUnpadded));
+  EXPECT_TRUE(IsValid("Zm8", Padding::kUnpadded));
+  EXPECT_TRUE(IsValid("Zm9v", Padding::kUnpadded));
+  EXPECT_TRUE(IsValid("-f__WW8", Padding::kUnpadded));
+
+  EXPECT_FALSE(IsValid("Z", Padding::kUnpadded));
+  EXPECT_FALSE(IsValid("Zm9vY", Padding::kUnpadded));
+  EXPECT_FALSE(IsValid("Zg==", Padding::kUnpadded));
+  EXPECT_FALSE(IsValid("Zm#", Padding::kUnpadded));
+
+  EXPECT_FALSE(IsValid("Zg"));
+  EXPECT_TRUE(IsValid("Zg=="));
+}
+
 TEST(Base64, Empty) {
   char buffer[] = "DO NOT TOUCH";
   EXPECT_EQ(0u, EncodedSize(0));
@@ -69,11 +69,28 @@ bool pw_Base64IsValid(const char* base64_data, size_t base64_size);
 
 namespace pw::base64 {
 
+// The character set used for encoding. The two alphabets differ only in the
+// characters used for values 62 and 63.
+enum class Alphabet {
+  kStandard,  // RFC 4648 section 4: uses + and /
+  kUrlSafe,   // RFC 4648 section 5: uses - and _
+};
+
+// Whether the final group of encoded data is padded to 4 characters with =.
+enum class Padding {
+  kPadded,
+  kUnpadded,
+};
+
 // Returns the size of the given number of bytes when encoded as Base64. Base64
 // encodes 3-byte groups into 4-character strings. The final group is padded to
-// be 3-bytes if it only has 1 or 2.
-constexpr size_t EncodedSize(size_t binary_size_bytes) {
-  return PW_BASE64_ENCODED_SIZE(binary_size_bytes);
+// be 3-bytes if it only has 1 or 2. Unpadded encodings omit the = characters,
+// so the final group is 2 or 3 characters instead.
+constexpr size_t EncodedSize(size_t binary_size_bytes,
+                             Padding padding = Padding::kPadded) {
+  return padding == Padding::kPadded
+             ? PW_BASE64_ENCODED_SIZE(binary_size_bytes)
+             : (binary_size_bytes * 4 + 2) / 3;
 }
 
 // Encodes the provided data in Base64 and writes the result to the buffer.
@@ -87,17 +104,35 @@ inline void Encode(std::span<const std::byte> binary, char* output) {
   pw_Base64Encode(binary.data(), binary.size_bytes(), output);
 }
 
+// Encodes the provided data in Base64 with the specified alphabet and padding.
+// Exactly EncodedSize(binary_size_bytes, padding) bytes will be written. The
+// output buffer *MUST* be large enough for the encoded output! The input and
+// output buffers MUST NOT be the same; encoding cannot occur in place.
+//
+// The resulting string in the output is NOT null-terminated!
+void Encode(std::span<const std::byte> binary,
+            char* output,
+            Alphabet alphabet,
+            Padding padding = Padding::kPadded);
+
 // Encodes the provided data in Base64 if the result fits in the provided
 // buffer. Returns the number of bytes written, which will be 0 if the output
 // buffer is too small.
-size_t Encode(std::span<const std::byte> binary, std::span<char> output_buffer);
-
-// Returns the maximum size of decoded Base64 data in bytes. base64_size_bytes
-// must be a multiple of 4, since Base64 encodes 3-byte groups into 4-character
-// strings. If the last 3-byte group has padding, the actual decoded size would
-// be 1 or 2 bytes less than MaxDecodedSize.
-constexpr size_t MaxDecodedSize(size_t base64_size_bytes) {
-  return PW_BASE64_MAX_DECODED_SIZE(base64_size_bytes);
+size_t Encode(std::span<const std::byte> binary,
+              std::span<char> output_buffer,
+              Alphabet alphabet = Alphabet::kStandard,
+              Padding padding = Padding::kPadded);
+
+// Returns the maximum size of decoded Base64 data in bytes. For padded data,
+// base64_size_bytes must be a multiple of 4, since Base64 encodes 3-byte groups
+// into 4-character strings. If the last 3-byte group has padding, the actual
+// decoded size would be 1 or 2 bytes less than MaxDecodedSize. For unpadded
+// data, the decoded size is exact.
+constexpr size_t MaxDecodedSize(size_t base64_size_bytes,
+                                Padding padding = Padding::kPadded) {
+  return padding == Padding::kPadded
+             ? PW_BASE64_MAX_DECODED_SIZE(base64_size_bytes)
+             : base64_size_bytes * 3 / 4;
 }
 
 // Decodes the provided Base64 data into raw binary. The output buffer *MUST* be
@@ -113,10 +148,17 @@ inline size_t Decode(std::string_view base64, void* output) {
   return pw_Base64Decode(base64.data(), base64.size(), output);
 }
 
+// Decodes the provided Base64 data with the specified padding. With
+// Padding::kUnpadded, the final group may be 2 or 3 characters instead of 4.
+// Otherwise, behaves the same as the two-argument Decode.
+size_t Decode(std::string_view base64, void* output, Padding padding);
+
 // Decodes the provided Base64 data, if the data is valid and fits in the output
 // buffer. Returns the number of bytes written, which will be 0 if the data is
 // invalid or doesn't fit.
-size_t Decode(std::string_view base64, std::span<std::byte> output_buffer);
+size_t Decode(std::string_view base64,
+              std::span<std::byte> output_buffer,
+              Padding padding = Padding::kPadded);
 
 // Returns true if the provided string is valid Base64 encoded data. Accepts
 // either the standard (+/) or URL-safe (-_) alphabets.
@@ -124,6 +166,10 @@ inline bool IsValid(std::string_view base64) {
   return pw_Base64IsValid(base64.data(), base64.size());
 }
 
+// Returns true if the provided string is valid Base64 encoded data with the
+// specified padding. Unpadded data must not contain = characters.
+bool IsValid(std::string_view base64, Padding padding);
+
 }  // namespace pw::base64
 
 #endif  // __cplusplus